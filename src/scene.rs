@@ -1,30 +1,110 @@
 use crate::prelude::*;
+use bevy::ecs::system::EntityCommands;
 use bevy::utils::tracing;
 use godot::engine::{
-    node::InternalMode, packed_scene::GenEditState, resource_loader::CacheMode, ResourceLoader,
+    node::InternalMode, packed_scene::GenEditState, resource_loader::CacheMode,
+    resource_loader::ThreadLoadStatus, ResourceLoader,
 };
+use std::sync::Arc;
+
+#[derive(Default)]
+pub(crate) struct PackedScenePlugin {
+    /// When enabled, spawned scenes are freed and re-instanced in place whenever their source
+    /// `PackedScene` asset changes on disk. Off by default so production builds pay nothing for
+    /// watching assets; turn it on for a fast level/design edit-reload loop.
+    ///
+    /// Only handle-based scenes ([`GodotScene::from_handle`]) are watched, since reload detection
+    /// piggybacks on Bevy's `Assets<ErasedGdResource>` `AssetEvent::Modified`. Scenes built with
+    /// [`GodotScene::from_resource`] hold their resource directly and are not reloaded either.
+    /// Path-based scenes ([`GodotScene::from_path`]/[`GodotScene::from_path_async`]) never enter
+    /// that asset store, so they are not reloaded; [`warn_unsupported_hot_reload_scenes`] logs a
+    /// warning the first time one is seen with this flag on.
+    pub(crate) hot_reload: bool,
+}
 
-pub(crate) struct PackedScenePlugin;
 impl Plugin for PackedScenePlugin {
     fn build(&self, app: &mut App) {
-        app.add_system(spawn_scene.in_base_set(CoreSet::PostUpdate));
+        app.init_resource::<SpawnedSceneNodes>();
+        app.add_system(request_async_scene_loads.in_base_set(CoreSet::PostUpdate));
+        app.add_system(
+            poll_async_scene_loads
+                .in_base_set(CoreSet::PostUpdate)
+                .after(request_async_scene_loads),
+        );
+        app.add_system(
+            spawn_scene
+                .in_base_set(CoreSet::PostUpdate)
+                .after(poll_async_scene_loads),
+        );
+        app.add_system(
+            despawn_scene
+                .in_base_set(CoreSet::PostUpdate)
+                .before(spawn_scene),
+        );
+
+        if self.hot_reload {
+            app.add_system(
+                hot_reload_scenes
+                    .in_base_set(CoreSet::PostUpdate)
+                    .before(spawn_scene),
+            );
+            app.add_system(warn_unsupported_hot_reload_scenes.in_base_set(CoreSet::PostUpdate));
+        }
     }
 }
 
+/// The node (and, if [`GodotScene::expose_children`] was used, the exposed child entities)
+/// instanced for a single spawned [`GodotScene`] entity.
+struct SpawnedScene {
+    node: ErasedGd,
+    exposed_children: Vec<Entity>,
+}
+
+/// Tracks the [`SpawnedScene`] for each spawned [`GodotScene`] entity, keyed by entity, so
+/// [`despawn_scene`] and [`hot_reload_scenes`] can still reach the node and its exposed children
+/// once the entity's own components are gone from the world or about to be replaced.
+///
+/// [`GodotScene::detach`] removes an entity's entry directly; callers that detach a node and
+/// want to keep it around must go through that rather than despawning the entity out from under
+/// this map, or [`despawn_scene`] will free a node the caller has since reparented elsewhere.
+#[derive(Resource, Default)]
+pub struct SpawnedSceneNodes(bevy::utils::HashMap<Entity, SpawnedScene>);
+
 /// A to-be-instanced-and-spawned Godot scene.
 ///
 /// [`GodotScene`]s that are spawned/inserted into the bevy world will be instanced from the provided
 /// handle/path and the instance will be added as an [`ErasedGd`] in the next PostUpdateFlush set.
 /// (see [`spawn_scene`])
-#[derive(Debug, Component)]
+#[derive(Component)]
 pub struct GodotScene {
     resource: GodotSceneResource,
     transform: Option<GodotSceneTransform>,
+    parent: Option<GodotSceneParent>,
+    expose_children: bool,
+    blueprint: Option<GodotSceneBlueprint>,
+}
+
+impl std::fmt::Debug for GodotScene {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GodotScene")
+            .field("resource", &self.resource)
+            .field("transform", &self.transform)
+            .field("parent", &self.parent)
+            .field("expose_children", &self.expose_children)
+            .field("blueprint", &self.blueprint.as_ref().map(|_| ".."))
+            .finish()
+    }
 }
 
+/// A post-spawn hook run right after a [`GodotScene`]'s [`ErasedGd`] is inserted, given the
+/// chance to attach further components (via the passed [`EntityCommands`]) before
+/// [`GodotSceneSpawned`] is added. See [`GodotScene::with_blueprint`].
+type GodotSceneBlueprint = Arc<dyn Fn(&mut EntityCommands, &ErasedGd) + Send + Sync>;
+
 #[derive(Debug)]
 enum GodotSceneResource {
     Path(String),
+    PathAsync(String),
     Handle(Handle<ErasedGdResource>),
     Resource(ErasedGdResource),
 }
@@ -35,16 +115,50 @@ enum GodotSceneTransform {
     Transform3D(Transform3D),
 }
 
+/// Where a [`GodotScene`] should be attached in the [`SceneTree`], instead of the default of
+/// the current scene root.
+#[derive(Debug)]
+enum GodotSceneParent {
+    /// Attach under the node of another (eventually-spawned) Bevy entity.
+    Entity(Entity),
+    /// Attach under an already-available Godot node.
+    Node(ErasedGd),
+}
+
 impl GodotScene {
     /// Instantiate the godot scene from the given path.
     ///
     /// Note that this will call [`ResourceLoader`].load() - which is a blocking load.
     /// If you want "preload" functionality, you should load your resources in a dedicated Bevy
     /// state into a Bevy [`Resource`], and use from_handle or from_resource.
+    ///
+    /// Path-based scenes are not reloaded by [`PackedScenePlugin::hot_reload`] — use
+    /// [`GodotScene::from_handle`] if you need that.
     pub fn from_path(path: &str) -> Self {
         Self {
             resource: GodotSceneResource::Path(path.to_string()),
             transform: None,
+            parent: None,
+            expose_children: false,
+            blueprint: None,
+        }
+    }
+
+    /// Instantiate the godot scene from the given path, using Godot's threaded resource loader.
+    ///
+    /// Unlike [`GodotScene::from_path`], this does not block the frame it's spawned in: the load
+    /// is kicked off via [`ResourceLoader::load_threaded_request`] and polled every `PostUpdate`
+    /// until it finishes, at which point the scene is instanced and spawned as normal. Use this
+    /// for scenes large enough to cause a noticeable hitch.
+    ///
+    /// Like [`GodotScene::from_path`], this is not reloaded by [`PackedScenePlugin::hot_reload`].
+    pub fn from_path_async(path: &str) -> Self {
+        Self {
+            resource: GodotSceneResource::PathAsync(path.to_string()),
+            transform: None,
+            parent: None,
+            expose_children: false,
+            blueprint: None,
         }
     }
 
@@ -53,6 +167,9 @@ impl GodotScene {
         Self {
             resource: GodotSceneResource::Handle(handle.clone()),
             transform: None,
+            parent: None,
+            expose_children: false,
+            blueprint: None,
         }
     }
 
@@ -61,6 +178,9 @@ impl GodotScene {
         Self {
             resource: GodotSceneResource::Resource(res),
             transform: None,
+            parent: None,
+            expose_children: false,
+            blueprint: None,
         }
     }
 
@@ -87,18 +207,347 @@ impl GodotScene {
         ));
         self
     }
+
+    /// Spawn this scene as a child of the node belonging to another entity, instead of the
+    /// current scene root.
+    ///
+    /// If `parent` hasn't spawned its own [`GodotScene`] yet, spawning is deferred until it has.
+    pub fn with_parent(mut self, parent: Entity) -> Self {
+        self.parent = Some(GodotSceneParent::Entity(parent));
+        self
+    }
+
+    /// Spawn this scene as a child of an already-available Godot node, instead of the current
+    /// scene root.
+    pub fn with_parent_node(mut self, parent: ErasedGd) -> Self {
+        self.parent = Some(GodotSceneParent::Node(parent));
+        self
+    }
+
+    /// Expose every descendant of the instanced scene as its own Bevy entity.
+    ///
+    /// Each descendant node gets an entity (parented via [`Parent`]/[`Children`] to the entity of
+    /// its Godot parent, so the scene root's direct children sit under this scene's entity)
+    /// holding an [`ErasedGd`] for that node and a [`GodotNodeName`]
+    /// with its Godot node name, so systems can `Query` and drive interior nodes (a mesh, a
+    /// collider, a marker) without manually walking down from the scene root.
+    pub fn expose_children(mut self) -> Self {
+        self.expose_children = true;
+        self
+    }
+
+    /// Register a post-spawn hook that runs right after this scene's [`ErasedGd`] is inserted,
+    /// letting gameplay components already authored on the entity (health, team, AI state) be
+    /// merged onto the instanced scene rather than being left to just gain an [`ErasedGd`].
+    ///
+    /// Turns [`GodotScene`] into a reusable "blueprint" primitive: spawn an entity with your own
+    /// gameplay components plus a `GodotScene` carrying a blueprint hook, and have those
+    /// components attached to the resulting instance once it's ready.
+    pub fn with_blueprint(
+        mut self,
+        blueprint: impl Fn(&mut EntityCommands, &ErasedGd) + Send + Sync + 'static,
+    ) -> Self {
+        self.blueprint = Some(Arc::new(blueprint));
+        self
+    }
+
+    /// Removes `entity`'s node from its parent in the [`SceneTree`] without freeing it.
+    ///
+    /// Unlike despawning the entity (which frees the node via [`despawn_scene`]), this leaves the
+    /// node alive and owned by the caller, for example to re-parent it elsewhere. This also
+    /// clears `entity`'s bookkeeping in [`SpawnedSceneNodes`], so a later despawn of `entity`
+    /// won't reach through and free a node the caller has since reparented.
+    pub fn detach(entity: Entity, instance: &ErasedGd, spawned_nodes: &mut SpawnedSceneNodes) {
+        spawned_nodes.0.remove(&entity);
+
+        let Some(mut node) = instance.share().try_cast::<Node>() else {
+            tracing::error!("attempted to detach a GodotScene node that did not inherit from Node");
+            return;
+        };
+        if let Some(mut parent) = node.get_parent() {
+            parent.remove_child(node.share());
+        }
+    }
 }
 
 #[derive(Component, Debug, Default)]
 struct GodotSceneSpawned;
 
+/// The Godot node name of an entity spawned for a scene descendant by
+/// [`GodotScene::expose_children`].
+#[derive(Component, Debug, Clone)]
+pub struct GodotNodeName(pub String);
+
+/// Marker for a [`GodotScene`] whose [`GodotSceneResource::PathAsync`] resource is currently
+/// being loaded by Godot's threaded [`ResourceLoader`].
+#[derive(Component, Debug)]
+struct GodotSceneLoading {
+    path: String,
+}
+
+/// Marker for a [`GodotScene`] whose threaded load ended in [`ThreadLoadStatus::THREAD_LOAD_FAILED`]
+/// or [`ThreadLoadStatus::THREAD_LOAD_INVALID_RESOURCE`], or whose parent entity (see
+/// [`GodotScene::with_parent`]) itself carries this marker. Left in place so the entity isn't
+/// retried every frame.
+#[derive(Component, Debug, Default)]
+struct GodotSceneLoadFailed;
+
+/// Outcome of resolving the node a [`GodotScene`] should be added as a child of.
+enum ParentResolution {
+    Ready(Gd<Node>),
+    /// An entity parent was requested but hasn't spawned its own node yet; retry next frame.
+    /// Also returned, after logging an error, when the parent node does not inherit from
+    /// [`Node`]; that case is retried (and logged) every frame as well.
+    Pending,
+    /// An entity parent was requested but itself has [`GodotSceneLoadFailed`], so it will never
+    /// spawn a node; the caller should give up rather than retry forever.
+    ParentFailed,
+}
+
+/// Resolves the node a [`GodotScene`] should be added as a child of. See [`ParentResolution`] for
+/// what each outcome means to the caller.
+fn resolve_parent_node(
+    parent: &Option<GodotSceneParent>,
+    parent_nodes: &Query<&ErasedGd, With<GodotSceneSpawned>>,
+    failed_parents: &Query<(), With<GodotSceneLoadFailed>>,
+    scene_tree: &mut SceneTreeRef,
+) -> ParentResolution {
+    let node = match parent {
+        None => return ParentResolution::Ready(scene_tree.get().get_current_scene().unwrap()),
+        Some(GodotSceneParent::Node(node)) => node,
+        Some(GodotSceneParent::Entity(entity)) => match parent_nodes.get(*entity) {
+            Ok(node) => node,
+            Err(_) if failed_parents.contains(*entity) => return ParentResolution::ParentFailed,
+            Err(_) => return ParentResolution::Pending,
+        },
+    };
+
+    match node.share().try_cast::<Node>() {
+        Some(node) => ParentResolution::Ready(node),
+        None => {
+            tracing::error!(
+                "GodotScene parent node did not inherit from Node, could not attach child"
+            );
+            ParentResolution::Pending
+        }
+    }
+}
+
+/// Recursively spawns a child Bevy entity for every descendant of `node`, mirroring the Godot
+/// node tree: each spawned entity is parented (via [`Parent`]/[`Children`]) to the entity spawned
+/// for its own Godot parent, starting from `parent_entity` for `node`'s direct children. Every
+/// spawned entity holds an [`ErasedGd`] plus a [`GodotNodeName`], and is appended to `spawned` so
+/// the caller can track and later despawn them. Used by [`GodotScene::expose_children`].
+fn expose_children(
+    commands: &mut Commands,
+    parent_entity: Entity,
+    node: &Gd<Node>,
+    spawned: &mut Vec<Entity>,
+) {
+    for i in 0..node.get_child_count(false) {
+        let Some(child) = node.get_child(i, false) else {
+            continue;
+        };
+
+        let child_entity = commands
+            .spawn((
+                ErasedGd::new(child.share()),
+                GodotNodeName(child.get_name().to_string()),
+            ))
+            .set_parent(parent_entity)
+            .id();
+        spawned.push(child_entity);
+
+        expose_children(commands, child_entity, &child, spawned);
+    }
+}
+
+fn apply_transform(transform: &Option<GodotSceneTransform>, instance: &ErasedGd) {
+    let Some(transform) = transform else { return };
+    match transform {
+        GodotSceneTransform::Transform2D(transform) => {
+            match instance.share().try_cast::<Node2D>() {
+                Some(mut node2d) => node2d.set_global_transform(*transform),
+                None => tracing::error!("attempted to spawn a scene with a transform on Node that did not inherit from Node2D, the transform was not set"),
+            }
+        }
+        GodotSceneTransform::Transform3D(transform) => {
+            match instance.share().try_cast::<Node3D>() {
+            Some(mut node3d) => node3d.set_global_transform(*transform),
+            None => tracing::error!("attempted to spawn a scene with a transform on Node that did not inherit from Node3D, the transform was not set"),
+        }
+        }
+    }
+}
+
+/// Kicks off a threaded load for any freshly-seen [`GodotScene::from_path_async`] scene.
+fn request_async_scene_loads(
+    mut commands: Commands,
+    new_scenes: Query<
+        (&GodotScene, Entity),
+        (
+            Without<GodotSceneSpawned>,
+            Without<GodotSceneLoading>,
+            Without<GodotSceneLoadFailed>,
+        ),
+    >,
+) {
+    for (scene, ent) in new_scenes.iter() {
+        let GodotSceneResource::PathAsync(path) = &scene.resource else {
+            continue;
+        };
+
+        ResourceLoader::singleton().load_threaded_request(
+            path.into(),
+            "PackedScene".into(),
+            false,
+            CacheMode::CACHE_MODE_REUSE,
+        );
+
+        commands
+            .entity(ent)
+            .insert(GodotSceneLoading { path: path.clone() });
+    }
+}
+
+/// Polls in-flight threaded loads started by [`request_async_scene_loads`], instancing and
+/// spawning the scene once Godot reports it as loaded.
+fn poll_async_scene_loads(
+    mut commands: Commands,
+    loading_scenes: Query<(&GodotScene, &GodotSceneLoading, Entity)>,
+    parent_nodes: Query<&ErasedGd, With<GodotSceneSpawned>>,
+    failed_parents: Query<(), With<GodotSceneLoadFailed>>,
+    mut scene_tree: SceneTreeRef,
+    mut spawned_nodes: ResMut<SpawnedSceneNodes>,
+) {
+    for (scene, loading, ent) in loading_scenes.iter() {
+        match ResourceLoader::singleton().load_threaded_get_status(loading.path.clone().into()) {
+            ThreadLoadStatus::THREAD_LOAD_IN_PROGRESS => continue,
+            ThreadLoadStatus::THREAD_LOAD_LOADED => {
+                let mut parent_node = match resolve_parent_node(
+                    &scene.parent,
+                    &parent_nodes,
+                    &failed_parents,
+                    &mut scene_tree,
+                ) {
+                    ParentResolution::Ready(node) => node,
+                    ParentResolution::Pending => {
+                        // Parent entity hasn't spawned its own node yet; try again next frame.
+                        continue;
+                    }
+                    ParentResolution::ParentFailed => {
+                        tracing::error!(
+                            "GodotScene's parent entity failed to load its own scene; giving up on this entity too"
+                        );
+                        commands
+                            .entity(ent)
+                            .remove::<GodotSceneLoading>()
+                            .insert(GodotSceneLoadFailed);
+                        continue;
+                    }
+                };
+
+                let packed_scene = ResourceLoader::singleton()
+                    .load_threaded_get(loading.path.clone().into())
+                    .expect("packed scene to load");
+
+                let instance = packed_scene
+                    .try_cast::<PackedScene>()
+                    .expect("resource to be a packed scene")
+                    .instantiate(GenEditState::GEN_EDIT_STATE_DISABLED)
+                    .unwrap();
+
+                parent_node.add_child(
+                    instance.share(),
+                    false,
+                    InternalMode::INTERNAL_MODE_DISABLED,
+                );
+
+                apply_transform(&scene.transform, &instance);
+
+                let mut exposed_children = Vec::new();
+                if scene.expose_children {
+                    expose_children(&mut commands, ent, &instance, &mut exposed_children);
+                }
+
+                let erased = ErasedGd::new(instance);
+                spawned_nodes.0.insert(
+                    ent,
+                    SpawnedScene {
+                        node: erased.share(),
+                        exposed_children,
+                    },
+                );
+
+                let mut entity_commands = commands.entity(ent);
+                entity_commands
+                    .remove::<GodotSceneLoading>()
+                    .insert(erased.share());
+                if let Some(blueprint) = &scene.blueprint {
+                    blueprint(&mut entity_commands, &erased);
+                }
+                entity_commands.insert(GodotSceneSpawned);
+            }
+            status => {
+                tracing::error!(
+                    "failed to thread-load godot scene at path {:?}: {:?}",
+                    loading.path,
+                    status
+                );
+                commands
+                    .entity(ent)
+                    .remove::<GodotSceneLoading>()
+                    .insert(GodotSceneLoadFailed);
+            }
+        }
+    }
+}
+
 fn spawn_scene(
     mut commands: Commands,
-    mut new_scenes: Query<(&mut GodotScene, Entity), Without<GodotSceneSpawned>>,
+    mut new_scenes: Query<
+        (&mut GodotScene, Entity),
+        (
+            Without<GodotSceneSpawned>,
+            Without<GodotSceneLoading>,
+            Without<GodotSceneLoadFailed>,
+        ),
+    >,
+    parent_nodes: Query<&ErasedGd, With<GodotSceneSpawned>>,
+    failed_parents: Query<(), With<GodotSceneLoadFailed>>,
     mut assets: ResMut<Assets<ErasedGdResource>>,
     mut scene_tree: SceneTreeRef,
+    mut spawned_nodes: ResMut<SpawnedSceneNodes>,
 ) {
     for (mut scene, ent) in new_scenes.iter_mut() {
+        if matches!(scene.resource, GodotSceneResource::PathAsync(_)) {
+            // Handled by `request_async_scene_loads`/`poll_async_scene_loads` instead. Checked
+            // before `resolve_parent_node` so these entities never touch the parent/scene tree
+            // lookup below, which may not be valid yet (e.g. no `current_scene` set while an
+            // async-loaded scene is still streaming in as the app's first scene).
+            continue;
+        }
+
+        let mut parent_node = match resolve_parent_node(
+            &scene.parent,
+            &parent_nodes,
+            &failed_parents,
+            &mut scene_tree,
+        ) {
+            ParentResolution::Ready(node) => node,
+            ParentResolution::Pending => {
+                // Parent entity hasn't spawned its own node yet; try again next frame.
+                continue;
+            }
+            ParentResolution::ParentFailed => {
+                tracing::error!(
+                    "GodotScene's parent entity failed to load its own scene; giving up on this entity too"
+                );
+                commands.entity(ent).insert(GodotSceneLoadFailed);
+                continue;
+            }
+        };
+
         let packed_scene = match &mut scene.resource {
             GodotSceneResource::Handle(handle) => assets
                 .get_mut(&handle)
@@ -111,6 +560,7 @@ fn spawn_scene(
                     CacheMode::CACHE_MODE_REUSE,
                 )
                 .expect("packed scene to load"),
+            GodotSceneResource::PathAsync(_) => unreachable!("filtered out above"),
             GodotSceneResource::Resource(res) => res.get(),
         };
 
@@ -120,32 +570,134 @@ fn spawn_scene(
             .instantiate(GenEditState::GEN_EDIT_STATE_DISABLED)
             .unwrap();
 
-        scene_tree.get().get_current_scene().unwrap().add_child(
+        parent_node.add_child(
             instance.share(),
             false,
             InternalMode::INTERNAL_MODE_DISABLED,
         );
 
-        if let Some(transform) = &scene.transform {
-            match transform {
-                GodotSceneTransform::Transform2D(transform) => {
-                    match instance.share().try_cast::<Node2D>() {
-                        Some(mut node2d) => node2d.set_global_transform(*transform),
-                        None => tracing::error!("attempted to spawn a scene with a transform on Node that did not inherit from Node3D, the transform was not set"),
-                    }
-                }
-                GodotSceneTransform::Transform3D(transform) => {
-                    match instance.share().try_cast::<Node3D>() {
-                    Some(mut node3d) => node3d.set_global_transform(*transform),
-                    None => tracing::error!("attempted to spawn a scene with a transform on Node that did not inherit from Node3D, the transform was not set"),
+        apply_transform(&scene.transform, &instance);
+
+        let mut exposed_children = Vec::new();
+        if scene.expose_children {
+            expose_children(&mut commands, ent, &instance, &mut exposed_children);
+        }
+
+        let erased = ErasedGd::new(instance);
+        spawned_nodes.0.insert(
+            ent,
+            SpawnedScene {
+                node: erased.share(),
+                exposed_children,
+            },
+        );
+
+        let mut entity_commands = commands.entity(ent);
+        entity_commands.insert(erased.share());
+        if let Some(blueprint) = &scene.blueprint {
+            blueprint(&mut entity_commands, &erased);
+        }
+        entity_commands.insert(GodotSceneSpawned);
+    }
+}
+
+/// Frees the Godot node of any [`GodotScene`] entity whose [`GodotSceneSpawned`] marker was
+/// removed this frame, including via a full entity despawn. Without this, instanced nodes would
+/// leak in the [`SceneTree`] since nothing else ever calls `queue_free()` on them.
+fn despawn_scene(
+    mut commands: Commands,
+    mut removed_scenes: RemovedComponents<GodotSceneSpawned>,
+    mut spawned_nodes: ResMut<SpawnedSceneNodes>,
+) {
+    for ent in removed_scenes.iter() {
+        let Some(spawned) = spawned_nodes.0.remove(&ent) else {
+            continue;
+        };
+        match spawned.node.share().try_cast::<Node>() {
+            Some(mut node) => node.queue_free(),
+            None => tracing::error!(
+                "despawned GodotScene entity's node did not inherit from Node, could not free it"
+            ),
+        }
+        // The exposed nodes are freed along with the root above; only their entities need
+        // cleaning up here.
+        for child in spawned.exposed_children {
+            commands.entity(child).despawn();
+        }
+    }
+}
+
+/// Opt-in (see [`PackedScenePlugin::hot_reload`]) system that frees and un-marks any
+/// handle-based [`GodotScene`] whose backing [`ErasedGdResource`] asset was just modified,
+/// letting [`spawn_scene`] pick it straight back up and re-instance it from the reloaded resource.
+/// The entity keeps its [`GodotScene`] (and thus its stored transform), so the new instance is
+/// re-parented and re-transformed exactly as it was the first time around.
+fn hot_reload_scenes(
+    mut commands: Commands,
+    mut asset_events: EventReader<AssetEvent<ErasedGdResource>>,
+    scenes: Query<(&GodotScene, Entity), With<GodotSceneSpawned>>,
+    mut spawned_nodes: ResMut<SpawnedSceneNodes>,
+) {
+    for event in asset_events.iter() {
+        let AssetEvent::Modified { handle } = event else {
+            continue;
+        };
+
+        for (scene, ent) in scenes.iter() {
+            let GodotSceneResource::Handle(scene_handle) = &scene.resource else {
+                continue;
+            };
+            if scene_handle != handle {
+                continue;
+            }
+
+            if let Some(spawned) = spawned_nodes.0.remove(&ent) {
+                match spawned.node.share().try_cast::<Node>() {
+                    Some(mut node) => node.queue_free(),
+                    None => tracing::error!(
+                        "hot-reloaded GodotScene entity's node did not inherit from Node, could not free it"
+                    ),
                 }
+                // Re-exposed once the reloaded instance spawns; the old exposed-child entities
+                // would otherwise leak on every reload cycle.
+                for child in spawned.exposed_children {
+                    commands.entity(child).despawn();
                 }
             }
+
+            commands
+                .entity(ent)
+                .remove::<GodotSceneSpawned>()
+                .remove::<ErasedGd>();
+        }
+    }
+}
+
+/// Marks an entity that [`warn_unsupported_hot_reload_scenes`] has already warned about, so the
+/// warning is only logged once per entity.
+#[derive(Component, Debug, Default)]
+struct GodotSceneHotReloadUnsupported;
+
+/// Opt-in (see [`PackedScenePlugin::hot_reload`]) system that warns once per entity when a
+/// path-based [`GodotScene`] ([`GodotScene::from_path`]/[`GodotScene::from_path_async`]) is used
+/// while hot-reload is enabled, since [`hot_reload_scenes`] only watches handle-based scenes.
+fn warn_unsupported_hot_reload_scenes(
+    mut commands: Commands,
+    new_scenes: Query<(&GodotScene, Entity), Without<GodotSceneHotReloadUnsupported>>,
+) {
+    for (scene, ent) in new_scenes.iter() {
+        if !matches!(
+            scene.resource,
+            GodotSceneResource::Path(_) | GodotSceneResource::PathAsync(_)
+        ) {
+            continue;
         }
 
-        commands
-            .entity(ent)
-            .insert(ErasedGd::new(instance))
-            .insert(GodotSceneSpawned);
+        tracing::warn!(
+            "PackedScenePlugin::hot_reload is enabled, but entity {ent:?}'s GodotScene was \
+             created from a path; only handle-based scenes (from_handle) are \
+             watched for changes and this entity will never be hot-reloaded"
+        );
+        commands.entity(ent).insert(GodotSceneHotReloadUnsupported);
     }
 }